@@ -0,0 +1,237 @@
+//! `#[derive(BotCommand)]`, implementing `tdlib::command::BotCommand` for an
+//! enum whose variants are `/command` names and whose fields are the
+//! command's whitespace-separated arguments.
+//!
+//! ```ignore
+//! #[derive(BotCommand)]
+//! #[command(prefix = "/", separator = " ")]
+//! enum Command {
+//!     #[command(description = "show this help")]
+//!     Help,
+//!     #[command(rename = "echo")]
+//!     Echo(String),
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+/// Container-level `#[command(...)]` options.
+struct ContainerAttrs {
+    prefix: String,
+    separator: String,
+}
+
+impl Default for ContainerAttrs {
+    fn default() -> Self {
+        Self {
+            prefix: "/".to_string(),
+            separator: " ".to_string(),
+        }
+    }
+}
+
+/// Variant-level `#[command(...)]` options.
+#[derive(Default)]
+struct VariantAttrs {
+    rename: Option<String>,
+    description: Option<String>,
+}
+
+fn parse_name_value(tokens: proc_macro2::TokenStream) -> Vec<(String, String)> {
+    let parsed: syn::punctuated::Punctuated<syn::MetaNameValue, syn::Token![,]> =
+        match syn::parse::Parser::parse2(
+            syn::punctuated::Punctuated::parse_terminated,
+            tokens,
+        ) {
+            Ok(parsed) => parsed,
+            Err(_) => return Vec::new(),
+        };
+
+    parsed
+        .into_iter()
+        .filter_map(|nv| {
+            let key = nv.path.get_ident()?.to_string();
+            let value = match &nv.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(s),
+                    ..
+                }) => s.value(),
+                _ => return None,
+            };
+            Some((key, value))
+        })
+        .collect()
+}
+
+fn container_attrs(attrs: &[syn::Attribute]) -> ContainerAttrs {
+    let mut result = ContainerAttrs::default();
+    for attr in attrs {
+        if !attr.path().is_ident("command") {
+            continue;
+        }
+        let Ok(tokens) = attr.meta.require_list().map(|list| list.tokens.clone()) else {
+            continue;
+        };
+        for (key, value) in parse_name_value(tokens) {
+            match key.as_str() {
+                "prefix" => result.prefix = value,
+                "separator" => result.separator = value,
+                _ => {}
+            }
+        }
+    }
+    result
+}
+
+fn variant_attrs(attrs: &[syn::Attribute]) -> VariantAttrs {
+    let mut result = VariantAttrs::default();
+    for attr in attrs {
+        if !attr.path().is_ident("command") {
+            continue;
+        }
+        let Ok(tokens) = attr.meta.require_list().map(|list| list.tokens.clone()) else {
+            continue;
+        };
+        for (key, value) in parse_name_value(tokens) {
+            match key.as_str() {
+                "rename" => result.rename = Some(value),
+                "description" => result.description = Some(value),
+                _ => {}
+            }
+        }
+    }
+    result
+}
+
+/// Derives `tdlib::command::BotCommand` for an enum of bot commands.
+#[proc_macro_derive(BotCommand, attributes(command))]
+pub fn derive_bot_command(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+
+    let Data::Enum(data) = &input.data else {
+        return syn::Error::new_spanned(&input, "BotCommand can only be derived for enums")
+            .to_compile_error()
+            .into();
+    };
+
+    let container = container_attrs(&input.attrs);
+    let prefix = LitStr::new(&container.prefix, ident.span());
+    let separator = LitStr::new(&container.separator, ident.span());
+
+    let mut match_arms = Vec::new();
+    let mut descriptions = Vec::new();
+
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let attrs = variant_attrs(&variant.attrs);
+        let command_name = attrs
+            .rename
+            .unwrap_or_else(|| variant_ident.to_string().to_lowercase());
+        let description = attrs.description.unwrap_or_default();
+        let command_lit = LitStr::new(&command_name, variant_ident.span());
+
+        descriptions.push(quote! {
+            out.push_str(#prefix);
+            out.push_str(#command_lit);
+            if !#description.is_empty() {
+                out.push_str(" - ");
+                out.push_str(#description);
+            }
+            out.push('\n');
+        });
+
+        let field_count = match &variant.fields {
+            Fields::Unit => 0,
+            Fields::Unnamed(fields) => fields.unnamed.len(),
+            Fields::Named(fields) => fields.named.len(),
+        };
+
+        let build_variant = match &variant.fields {
+            Fields::Unit => quote! { #ident::#variant_ident },
+            Fields::Unnamed(fields) => {
+                let parsed = fields.unnamed.iter().enumerate().map(|(index, field)| {
+                    let ty = &field.ty;
+                    quote! {
+                        args[#index].parse::<#ty>().map_err(|e| {
+                            tdlib::command::ParseError::ArgumentParseFailed {
+                                argument: args[#index].to_string(),
+                                error: e.to_string(),
+                            }
+                        })?
+                    }
+                });
+                quote! { #ident::#variant_ident(#(#parsed),*) }
+            }
+            Fields::Named(fields) => {
+                let parsed = fields.named.iter().enumerate().map(|(index, field)| {
+                    let field_ident = field.ident.as_ref().unwrap();
+                    let ty = &field.ty;
+                    quote! {
+                        #field_ident: args[#index].parse::<#ty>().map_err(|e| {
+                            tdlib::command::ParseError::ArgumentParseFailed {
+                                argument: args[#index].to_string(),
+                                error: e.to_string(),
+                            }
+                        })?
+                    }
+                });
+                quote! { #ident::#variant_ident { #(#parsed),* } }
+            }
+        };
+
+        match_arms.push(quote! {
+            #command_lit => {
+                // `splitn` caps the number of splits at the field count, so
+                // the last field absorbs the rest of the text verbatim
+                // instead of being cut at the first separator in it.
+                let args: Vec<&str> = if rest.is_empty() {
+                    Vec::new()
+                } else {
+                    rest.splitn(#field_count.max(1), #separator).collect()
+                };
+
+                if args.len() != #field_count {
+                    return Err(tdlib::command::ParseError::WrongNumberOfArguments {
+                        expected: #field_count,
+                        found: args.len(),
+                    });
+                }
+                Ok(#build_variant)
+            }
+        });
+    }
+
+    let expanded = quote! {
+        impl tdlib::command::BotCommand for #ident {
+            fn parse(text: &str, bot_username: &str) -> Result<Self, tdlib::command::ParseError> {
+                let text = text
+                    .strip_prefix(#prefix)
+                    .ok_or_else(|| tdlib::command::ParseError::UnknownCommand(text.to_string()))?;
+
+                let mut parts = text.splitn(2, #separator);
+                let command = parts.next().unwrap_or_default();
+                let rest = parts.next().unwrap_or_default();
+
+                let command = command
+                    .strip_suffix(&format!("@{bot_username}"))
+                    .unwrap_or(command);
+
+                match command {
+                    #(#match_arms)*
+                    other => Err(tdlib::command::ParseError::UnknownCommand(other.to_string())),
+                }
+            }
+
+            fn descriptions() -> String {
+                let mut out = String::new();
+                #(#descriptions)*
+                out
+            }
+        }
+    };
+
+    expanded.into()
+}
@@ -1,14 +1,6 @@
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
-};
-use tdlib::{
-    self,
-    enums::{AuthorizationState, InputMessageContent, Update, self},
-    functions,
-    types::{FormattedText, InputMessageText, TdlibParameters},
-};
-use tokio::sync::mpsc::{self, Receiver, Sender};
+use tdlib::enums::{self, InputMessageContent, Update};
+use tdlib::types::{FormattedText, InputMessageText, TdlibParameters};
+use tdlib::{BoundClient, Client, ClientIdentifier, ConsoleAuthStateHandler, Worker};
 
 fn ask_user(string: &str) -> String {
     println!("{}", string);
@@ -17,11 +9,8 @@ fn ask_user(string: &str) -> String {
     input.trim().to_string()
 }
 
-async fn handle_update(update: Update, auth_tx: &Sender<AuthorizationState>, client_id: i32) {
+async fn handle_update(update: Update, client: &BoundClient) {
     match update {
-        Update::AuthorizationState(update) => {
-            auth_tx.send(update.authorization_state).await.unwrap();
-        }
         Update::NewChat(data) => {
             let chat = data.chat;
             let title = chat.title;
@@ -46,120 +35,55 @@ async fn handle_update(update: Update, auth_tx: &Sender<AuthorizationState>, cli
             println!("message: {content:?}");
             let msg = ask_user("Do you want to reply? if not leave empty");
             if !msg.is_empty() {
-                reply(msg, chat_id, message.id, client_id).await;
+                reply(msg, chat_id, message.id, client).await;
             }
         }
         _ => (),
     }
 }
 
-async fn handle_authorization_state(
-    client_id: i32,
-    mut auth_rx: Receiver<AuthorizationState>,
-    run_flag: Arc<AtomicBool>,
-) -> Receiver<AuthorizationState> {
-    while let Some(state) = auth_rx.recv().await {
-        match state {
-            AuthorizationState::WaitTdlibParameters => {
-                let parameters = TdlibParameters {
-                    database_directory: "bot_db".to_string(),
-                    api_id: env!("API_ID").parse::<i32>().unwrap(),
-                    api_hash: env!("API_HASH").to_string(),
-                    system_language_code: "en".to_string(),
-                    device_model: "Desktop".to_string(),
-                    application_version: "0.1".to_string(),
-                    ..Default::default()
-                };
-
-                let response = functions::set_tdlib_parameters(parameters, client_id).await;
-                if let Err(error) = response {
-                    println!("{}", error.message);
-                }
-            }
-            AuthorizationState::WaitPhoneNumber => loop {
-                let response = functions::check_authentication_bot_token(
-                    env!("BOT_TOKEN").to_string(),
-                    client_id,
-                )
-                .await;
-                match response {
-                    Ok(_) => break,
-                    Err(e) => println!("{}", e.message),
-                }
-            },
-            AuthorizationState::Ready => {
-                break;
-            }
-            AuthorizationState::Closed => {
-                // Set the flag to false to stop receiving updates from the
-                // spawned task
-                run_flag.store(false, Ordering::Release);
-                break;
-            }
-            AuthorizationState::Closing => {
-                println!("error 500");
-                break;
-            }
-            AuthorizationState::WaitEncryptionKey(_) => {
-                let response = functions::check_database_encryption_key(
-                    option_env!("PASSWD").unwrap_or_default().to_string(),
-                    client_id,
-                )
-                .await;
-                match response {
-                    Ok(_) => (),
-                    Err(e) => println!("{}", e.message),
-                }
-            }
-            _ => (),
-        }
-    }
-
-    auth_rx
-}
-
 #[tokio::main]
 async fn main() {
-    // Create the client object
-    let client_id = tdlib::create_client();
-
-    // Create a mpsc channel for handling AuthorizationState updates separately
-    // from the task
-    let (auth_tx, auth_rx) = mpsc::channel(5);
-
-    // Create a flag to make it possible to stop receiving updates
-    let run_flag = Arc::new(AtomicBool::new(true));
-    let run_flag_clone = run_flag.clone();
-
-    // Spawn a task to receive updates/responses
-    let handle = tokio::spawn(async move {
-        while run_flag_clone.load(Ordering::Acquire) {
-            if let Some((update, _client_id)) = tdlib::receive() {
-                handle_update(update, &auth_tx, client_id).await;
-            }
-        }
-    });
+    // A single worker owns the receive loop; any number of clients can be
+    // bound to it, sharing it instead of each spawning their own.
+    let worker = Worker::builder().build();
+
+    let parameters = TdlibParameters::builder()
+        .database_directory("bot_db")
+        .api_id(env!("API_ID").parse::<i32>().unwrap())
+        .api_hash(env!("API_HASH"))
+        .system_language_code("en")
+        .device_model("Desktop")
+        .application_version("0.1")
+        .build()
+        .expect("invalid tdlib parameters");
+
+    let client = Client::builder()
+        .with_tdlib_parameters(parameters)
+        .build()
+        .expect("invalid tdlib parameters");
+    let mut client = worker.bind_client(client).await;
 
     // Set a fairly low verbosity level. We mainly do this because tdlib
     // requires to perform a random request with the client to start receiving
     // updates for it.
-    functions::set_log_verbosity_level(2, client_id)
+    tdlib::functions::set_log_verbosity_level(2, client.id())
         .await
         .unwrap();
 
-    // Handle the authorization state to authenticate the client
-    let auth_rx = handle_authorization_state(client_id, auth_rx, run_flag.clone()).await;
+    // Drive the client to `Ready` with the bot token we already know,
+    // prompting on stdin for anything else the login flow needs.
+    let identifier = ClientIdentifier::BotToken(env!("BOT_TOKEN").to_string());
+    let auth_handler = ConsoleAuthStateHandler::new(identifier);
+    client
+        .authorize(&auth_handler)
+        .await
+        .expect("authorization failed");
 
     println!("ready");
 
     // Run the get_me() method to get user informations
-    // FIXME: Delete this once moved to tdjson 1.8.5
-    // The code below crashes when tring to get bot info on tdjson 1.8.2,
-    // but from my testing it does work fine when tdjson 1.8.5 is installed
-    // Please keep in mind that tdjson 1.8.5 is not fully compatiple with tdlib-rs 0.2
-    // and will likely break telegrand so I would suggest to wait for the new tdlib Release
-
-    let me_response = functions::get_me(client_id).await;
+    let me_response = client.get_me().await;
     match me_response {
         Ok(data) => {
             let enums::User::User(user) = data;
@@ -169,16 +93,14 @@ async fn main() {
     }
 
     // Tell the client to close
-    //functions::close(client_id).await.unwrap();
-
-    // Handle the authorization state to wait for the "Closed" state
-    handle_authorization_state(client_id, auth_rx, run_flag.clone()).await;
+    //client.stop().await.unwrap();
 
-    // Wait for the previously spawned task to end the execution
-    handle.await.unwrap();
+    while let Some(update) = client.next_update().await {
+        handle_update(update, &client).await;
+    }
 }
 
-async fn reply(msg: String, chat_id: i64, reply_to_message_id: i64, client_id: i32) {
+async fn reply(msg: String, chat_id: i64, reply_to_message_id: i64, client: &BoundClient) {
     let text = FormattedText {
         text: msg,
         ..Default::default()
@@ -189,16 +111,16 @@ async fn reply(msg: String, chat_id: i64, reply_to_message_id: i64, client_id: i
         clear_draft: true,
     };
     let input_message_content = InputMessageContent::InputMessageText(content);
-    functions::send_message(
+    tdlib::functions::send_message(
         chat_id,
         0,
         reply_to_message_id,
         None,
         None,
         input_message_content,
-        client_id,
+        client.id(),
     )
     .await
     .expect("Failed to send a message");
     println!("message sent");
-}
\ No newline at end of file
+}
@@ -0,0 +1,85 @@
+//! Demonstrates `#[derive(BotCommand)]` (requires the `macros` feature)
+//! parsing `/command arg1 arg2` text out of incoming messages, instead of
+//! handling the raw message content by hand as `bot.rs` does.
+
+use tdlib::command::BotCommand;
+use tdlib::enums::{InputMessageContent, MessageContent, Update};
+use tdlib::types::{FormattedText, InputMessageText, TdlibParameters};
+use tdlib::{BoundClient, Client, ClientIdentifier, ConsoleAuthStateHandler, Worker};
+
+const BOT_USERNAME: &str = "mybot";
+
+#[derive(tdlib::BotCommand)]
+#[command(prefix = "/", separator = " ")]
+enum Command {
+    #[command(description = "show this help")]
+    Help,
+    #[command(description = "say hi back")]
+    Start,
+    #[command(description = "reply with the given text")]
+    Echo(String),
+}
+
+async fn handle_command(command: Command, chat_id: i64, client: &BoundClient) {
+    let reply_text = match command {
+        Command::Help => Command::descriptions(),
+        Command::Start => "Hi!".to_string(),
+        Command::Echo(text) => text,
+    };
+
+    let content = InputMessageContent::InputMessageText(InputMessageText {
+        text: FormattedText {
+            text: reply_text,
+            ..Default::default()
+        },
+        disable_web_page_preview: true,
+        clear_draft: true,
+    });
+
+    tdlib::functions::send_message(chat_id, 0, 0, None, None, content, client.id())
+        .await
+        .expect("Failed to send a message");
+}
+
+#[tokio::main]
+async fn main() {
+    let worker = Worker::builder().build();
+
+    let parameters = TdlibParameters::builder()
+        .database_directory("bot_commands_db")
+        .api_id(env!("API_ID").parse::<i32>().unwrap())
+        .api_hash(env!("API_HASH"))
+        .system_language_code("en")
+        .device_model("Desktop")
+        .application_version("0.1")
+        .build()
+        .expect("invalid tdlib parameters");
+
+    let client = Client::builder()
+        .with_tdlib_parameters(parameters)
+        .build()
+        .expect("invalid tdlib parameters");
+    let mut client = worker.bind_client(client).await;
+
+    let identifier = ClientIdentifier::BotToken(env!("BOT_TOKEN").to_string());
+    let auth_handler = ConsoleAuthStateHandler::new(identifier);
+    client
+        .authorize(&auth_handler)
+        .await
+        .expect("authorization failed");
+
+    while let Some(update) = client.next_update().await {
+        let Update::NewMessage(data) = update else {
+            continue;
+        };
+
+        let MessageContent::MessageText(text) = data.message.content else {
+            continue;
+        };
+
+        match Command::parse(&text.text.text, BOT_USERNAME) {
+            Ok(command) => handle_command(command, data.message.chat_id, &client).await,
+            Err(error) => println!("ignoring non-command message: {error}"),
+        }
+    }
+}
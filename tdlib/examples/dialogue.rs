@@ -0,0 +1,99 @@
+//! A minimal multi-step conversation built on [`tdlib::dialogue`].
+//!
+//! The bot asks for a name, answers the next message from that chat with
+//! "Nice to meet you, {name}!", the one after with "Hello again, {name}!",
+//! and then starts over. `InMemStorage` is swapped for `RedisStorage` or
+//! `SqliteStorage` (behind their cargo features) to survive a restart.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tdlib::dialogue::{Dialogue, InMemStorage};
+use tdlib::enums::{InputMessageContent, MessageContent, Update};
+use tdlib::types::{FormattedText, InputMessageText, TdlibParameters};
+use tdlib::{BoundClient, Client, ClientIdentifier, ConsoleAuthStateHandler, Worker};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum State {
+    WaitingForName,
+    WaitingForGreeting { name: String },
+}
+
+async fn send_text(client: &BoundClient, chat_id: i64, text: String) {
+    let content = InputMessageContent::InputMessageText(InputMessageText {
+        text: FormattedText {
+            text,
+            ..Default::default()
+        },
+        disable_web_page_preview: true,
+        clear_draft: true,
+    });
+
+    tdlib::functions::send_message(chat_id, 0, 0, None, None, content, client.id())
+        .await
+        .expect("Failed to send a message");
+}
+
+#[tokio::main]
+async fn main() {
+    let worker = Worker::builder().build();
+
+    let parameters = TdlibParameters::builder()
+        .database_directory("dialogue_db")
+        .api_id(env!("API_ID").parse::<i32>().unwrap())
+        .api_hash(env!("API_HASH"))
+        .system_language_code("en")
+        .device_model("Desktop")
+        .application_version("0.1")
+        .build()
+        .expect("invalid tdlib parameters");
+
+    let client = Client::builder()
+        .with_tdlib_parameters(parameters)
+        .build()
+        .expect("invalid tdlib parameters");
+    let mut client = worker.bind_client(client).await;
+
+    let identifier = ClientIdentifier::BotToken(env!("BOT_TOKEN").to_string());
+    let auth_handler = ConsoleAuthStateHandler::new(identifier);
+    client
+        .authorize(&auth_handler)
+        .await
+        .expect("authorization failed");
+
+    let storage = Arc::new(InMemStorage::<State>::new());
+
+    while let Some(update) = client.next_update().await {
+        let Update::NewMessage(data) = update else {
+            continue;
+        };
+
+        let MessageContent::MessageText(text) = data.message.content else {
+            continue;
+        };
+
+        let chat_id = data.message.chat_id;
+        // This example only needs one dialogue per chat, so the user id
+        // half of the key is left at a fixed value.
+        let dialogue = Dialogue::new(storage.clone(), chat_id, 0);
+
+        match dialogue.get().await.unwrap() {
+            None => {
+                send_text(&client, chat_id, "What's your name?".to_string()).await;
+                dialogue.update(State::WaitingForName).await.unwrap();
+            }
+            Some(State::WaitingForName) => {
+                let name = text.text.text;
+                send_text(&client, chat_id, format!("Nice to meet you, {name}!")).await;
+                dialogue
+                    .update(State::WaitingForGreeting { name })
+                    .await
+                    .unwrap();
+            }
+            Some(State::WaitingForGreeting { name }) => {
+                send_text(&client, chat_id, format!("Hello again, {name}!")).await;
+                dialogue.exit().await.unwrap();
+            }
+        }
+    }
+}
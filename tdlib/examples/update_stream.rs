@@ -0,0 +1,49 @@
+//! Consuming updates through [`tdlib::UpdateStream`] instead of one big
+//! `match`, composing with standard `tokio-stream` adaptors.
+
+use tdlib::enums::{MessageContent, Update};
+use tdlib::types::TdlibParameters;
+use tdlib::{Client, ClientIdentifier, ConsoleAuthStateHandler, Worker};
+use tokio_stream::StreamExt;
+
+#[tokio::main]
+async fn main() {
+    let worker = Worker::builder().build();
+
+    let parameters = TdlibParameters::builder()
+        .database_directory("update_stream_db")
+        .api_id(env!("API_ID").parse::<i32>().unwrap())
+        .api_hash(env!("API_HASH"))
+        .system_language_code("en")
+        .device_model("Desktop")
+        .application_version("0.1")
+        .build()
+        .expect("invalid tdlib parameters");
+
+    let client = Client::builder()
+        .with_tdlib_parameters(parameters)
+        .build()
+        .expect("invalid tdlib parameters");
+    let mut client = worker.bind_client(client).await;
+
+    let identifier = ClientIdentifier::BotToken(env!("BOT_TOKEN").to_string());
+    let auth_handler = ConsoleAuthStateHandler::new(identifier);
+    client
+        .authorize(&auth_handler)
+        .await
+        .expect("authorization failed");
+
+    let (_client, stream) = client.into_stream();
+
+    let mut messages = stream.filter_map_update(|update| match update {
+        Update::NewMessage(data) => match data.message.content {
+            MessageContent::MessageText(text) => Some(text.text.text),
+            _ => None,
+        },
+        _ => None,
+    });
+
+    while let Some(text) = messages.next().await {
+        println!("message: {text}");
+    }
+}
@@ -0,0 +1,161 @@
+//! Pluggable authorization flow for [`BoundClient`](crate::BoundClient)s.
+//!
+//! The `bot` example's `handle_authorization_state` function used to
+//! hard-code the whole login state machine inline. [`AuthStateHandler`] extracts each step into its own
+//! callback so alternative front-ends (a GUI, a test harness, ...) can
+//! drive the flow without reimplementing it.
+
+use async_trait::async_trait;
+
+use crate::types::Error;
+
+/// Identifies which credential a client authenticates with.
+#[derive(Debug, Clone)]
+pub enum ClientIdentifier {
+    /// Log in as a user with the given phone number.
+    PhoneNumber(String),
+    /// Log in as a bot with the given bot token.
+    BotToken(String),
+}
+
+/// Drives the interactive parts of tdlib's authorization state machine.
+///
+/// An implementation is asked for exactly the piece of information each
+/// `AuthorizationState` variant needs. The phone number / bot token is
+/// already known from [`Self::identifier`], so [`Self::handle_phone_number`]
+/// and [`Self::handle_bot_token`] default to it; [`ConsoleAuthStateHandler`]
+/// prompts on stdin for everything else (code, password, ...) and
+/// [`ChannelAuthStateHandler`] reads it from an `mpsc` channel so secrets can
+/// be supplied programmatically.
+#[async_trait]
+pub trait AuthStateHandler: Sync {
+    /// Returns the local encryption key for `AuthorizationState::WaitEncryptionKey`.
+    async fn handle_encryption_key(&self) -> String {
+        String::new()
+    }
+
+    /// Returns which identifier to authenticate with for
+    /// `AuthorizationState::WaitPhoneNumber`.
+    async fn identifier(&self) -> ClientIdentifier;
+
+    /// Returns the phone number to send for `AuthorizationState::WaitPhoneNumber`
+    /// when [`Self::identifier`] is [`ClientIdentifier::PhoneNumber`].
+    ///
+    /// Defaults to the number already carried by [`Self::identifier`];
+    /// override this only if a phone-number login needs to obtain it some
+    /// other way (e.g. prompting, since the identifier is not always known
+    /// up front).
+    async fn handle_phone_number(&self) -> String {
+        match self.identifier().await {
+            ClientIdentifier::PhoneNumber(phone_number) => phone_number,
+            ClientIdentifier::BotToken(_) => String::new(),
+        }
+    }
+
+    /// Returns the bot token to send for `AuthorizationState::WaitPhoneNumber`
+    /// when [`Self::identifier`] is [`ClientIdentifier::BotToken`].
+    ///
+    /// Defaults to the token already carried by [`Self::identifier`]; see
+    /// [`Self::handle_phone_number`] for when to override it.
+    async fn handle_bot_token(&self) -> String {
+        match self.identifier().await {
+            ClientIdentifier::BotToken(bot_token) => bot_token,
+            ClientIdentifier::PhoneNumber(_) => String::new(),
+        }
+    }
+
+    /// Returns the login code for `AuthorizationState::WaitCode`.
+    async fn handle_code(&self) -> String;
+
+    /// Returns the 2FA password for `AuthorizationState::WaitPassword`.
+    async fn handle_password(&self) -> String;
+
+    /// Called when tdlib rejects a request sent during authorization (the
+    /// client's parameters or a value a previous callback returned), with
+    /// the error it returned. The default implementation just prints it.
+    fn handle_error(&self, error: Error) {
+        println!("{}", error.message);
+    }
+}
+
+/// An [`AuthStateHandler`] that prompts on stdin for the login code and the
+/// 2FA password. The phone number / bot token come from its identifier and
+/// the encryption key is the empty default; neither is prompted for.
+pub struct ConsoleAuthStateHandler {
+    identifier: ClientIdentifier,
+}
+
+impl ConsoleAuthStateHandler {
+    /// Creates a handler that will authenticate with `identifier`, prompting
+    /// on stdin for the login code and password.
+    pub fn new(identifier: ClientIdentifier) -> Self {
+        Self { identifier }
+    }
+
+    fn prompt(prompt: &str) -> String {
+        println!("{prompt}");
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input).unwrap();
+        input.trim().to_string()
+    }
+}
+
+#[async_trait]
+impl AuthStateHandler for ConsoleAuthStateHandler {
+    async fn identifier(&self) -> ClientIdentifier {
+        self.identifier.clone()
+    }
+
+    async fn handle_code(&self) -> String {
+        Self::prompt("Enter the login code you received:")
+    }
+
+    async fn handle_password(&self) -> String {
+        Self::prompt("Enter your 2FA password:")
+    }
+}
+
+/// An [`AuthStateHandler`] backed by an [`mpsc::Receiver<String>`], so
+/// callers (a GUI, a test) can feed secrets in as they become available
+/// instead of blocking on stdin.
+pub struct ChannelAuthStateHandler {
+    identifier: ClientIdentifier,
+    receiver: tokio::sync::Mutex<tokio::sync::mpsc::Receiver<String>>,
+}
+
+impl ChannelAuthStateHandler {
+    /// Creates a handler that will authenticate with `identifier`, reading
+    /// the login code and 2FA password from `receiver` in the order they are
+    /// asked for. The encryption key is the empty default and is not read
+    /// from `receiver`.
+    pub fn new(identifier: ClientIdentifier, receiver: tokio::sync::mpsc::Receiver<String>) -> Self {
+        Self {
+            identifier,
+            receiver: tokio::sync::Mutex::new(receiver),
+        }
+    }
+
+    async fn recv(&self) -> String {
+        self.receiver
+            .lock()
+            .await
+            .recv()
+            .await
+            .expect("ChannelAuthStateHandler sender dropped while authorization was in progress")
+    }
+}
+
+#[async_trait]
+impl AuthStateHandler for ChannelAuthStateHandler {
+    async fn identifier(&self) -> ClientIdentifier {
+        self.identifier.clone()
+    }
+
+    async fn handle_code(&self) -> String {
+        self.recv().await
+    }
+
+    async fn handle_password(&self) -> String {
+        self.recv().await
+    }
+}
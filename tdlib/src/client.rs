@@ -0,0 +1,211 @@
+//! Typed handles for a single tdlib client.
+
+use tokio::sync::mpsc::Receiver;
+
+use crate::auth::{AuthStateHandler, ClientIdentifier};
+use crate::enums::{AuthorizationState, Update};
+use crate::params::ParamError;
+use crate::types::TdlibParameters;
+use crate::worker::SenderGuard;
+use crate::{create_client, functions, types};
+
+/// A tdlib client that has not been bound to a [`crate::Worker`] yet.
+///
+/// Build one with [`Client::builder`], then hand it to
+/// [`crate::Worker::bind_client`] to start receiving its updates.
+pub struct Client {
+    id: i32,
+    tdlib_parameters: TdlibParameters,
+}
+
+impl Client {
+    /// Creates a [`ClientBuilder`].
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// The client id tdlib assigned this client.
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+}
+
+/// Builder for [`Client`].
+#[derive(Default)]
+pub struct ClientBuilder {
+    tdlib_parameters: Option<TdlibParameters>,
+}
+
+impl ClientBuilder {
+    /// Sets the parameters sent in response to
+    /// `AuthorizationState::WaitTdlibParameters`.
+    pub fn with_tdlib_parameters(mut self, tdlib_parameters: TdlibParameters) -> Self {
+        self.tdlib_parameters = Some(tdlib_parameters);
+        self
+    }
+
+    /// Validates the parameters and allocates the tdlib client id.
+    ///
+    /// Rejects a zero `api_id` or empty `api_hash` up front, the same way
+    /// [`crate::params::TdlibParametersBuilder::build`] does, instead of
+    /// letting an obviously-invalid `TdlibParameters` reach tdjson.
+    pub fn build(self) -> Result<Client, ParamError> {
+        let tdlib_parameters = self.tdlib_parameters.unwrap_or_default();
+        crate::params::validate(&tdlib_parameters)?;
+
+        Ok(Client {
+            id: create_client(),
+            tdlib_parameters,
+        })
+    }
+}
+
+/// A [`Client`] bound to a [`crate::Worker`], receiving its own stream of
+/// updates and exposing the tdlib functions with `client_id` already
+/// captured.
+pub struct BoundClient {
+    client: Client,
+    updates: Receiver<Update>,
+    // Unbinds the client from the `Worker` it came from on drop. Unused
+    // outside of that, so the field is never read directly.
+    #[allow(dead_code)]
+    guard: SenderGuard,
+}
+
+impl BoundClient {
+    pub(crate) fn new(client: Client, updates: Receiver<Update>, guard: SenderGuard) -> Self {
+        Self {
+            client,
+            updates,
+            guard,
+        }
+    }
+
+    /// The client id tdlib assigned this client.
+    pub fn id(&self) -> i32 {
+        self.client.id
+    }
+
+    /// The parameters this client was built with.
+    pub fn tdlib_parameters(&self) -> &TdlibParameters {
+        &self.client.tdlib_parameters
+    }
+
+    /// Waits for the next update addressed to this client.
+    pub async fn next_update(&mut self) -> Option<Update> {
+        self.updates.recv().await
+    }
+
+    /// Converts this client into a [`crate::UpdateStream`], for composing
+    /// with `futures`/`tokio-stream` adaptors instead of polling
+    /// [`Self::next_update`] in a loop.
+    pub fn into_stream(self) -> (Client, crate::UpdateStream) {
+        (
+            self.client,
+            crate::UpdateStream::new(self.updates, self.guard),
+        )
+    }
+
+    /// Sends `SetTdlibParameters` with the parameters this client was built
+    /// with. Called automatically by [`Self::authorize`] in response to
+    /// `AuthorizationState::WaitTdlibParameters`.
+    pub async fn set_tdlib_parameters(&self) -> Result<(), types::Error> {
+        functions::set_tdlib_parameters(self.client.tdlib_parameters.clone(), self.id())
+            .await
+            .map(|_| ())
+    }
+
+    /// Wraps [`functions::get_me`] with this client's id.
+    pub async fn get_me(&self) -> Result<crate::enums::User, types::Error> {
+        functions::get_me(self.id()).await
+    }
+
+    /// Tells tdlib to close this client.
+    pub async fn stop(&self) -> Result<(), types::Error> {
+        functions::close(self.id()).await
+    }
+
+    /// Waits until this client reaches the given [`crate::enums::AuthorizationState`]
+    /// variant, as compared by discriminant (payloads are ignored).
+    pub async fn wait_client_state(
+        &mut self,
+        state: std::mem::Discriminant<crate::enums::AuthorizationState>,
+    ) -> Option<crate::enums::AuthorizationState> {
+        while let Some(update) = self.next_update().await {
+            if let Update::AuthorizationState(update) = update {
+                if std::mem::discriminant(&update.authorization_state) == state {
+                    return Some(update.authorization_state);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Drives tdlib's authorization state machine to completion, asking
+    /// `handler` for whatever each state needs instead of hard-coding the
+    /// flow. Non-authorization updates that arrive while this runs are
+    /// dropped, so a later [`Self::next_update`] does not return them; only
+    /// updates that arrive after this returns reach your own loop.
+    ///
+    /// Returns once the client reaches `Ready` or `Closed`.
+    pub async fn authorize(&mut self, handler: &dyn AuthStateHandler) -> Result<(), types::Error> {
+        while let Some(update) = self.next_update().await {
+            let Update::AuthorizationState(update) = update else {
+                continue;
+            };
+
+            match update.authorization_state {
+                AuthorizationState::WaitTdlibParameters => {
+                    if let Err(error) = self.set_tdlib_parameters().await {
+                        handler.handle_error(error);
+                    }
+                }
+                AuthorizationState::WaitEncryptionKey(_) => {
+                    let key = handler.handle_encryption_key().await;
+                    if let Err(error) = functions::check_database_encryption_key(key, self.id()).await
+                    {
+                        handler.handle_error(error);
+                    }
+                }
+                AuthorizationState::WaitPhoneNumber => match handler.identifier().await {
+                    ClientIdentifier::PhoneNumber(_) => {
+                        let phone_number = handler.handle_phone_number().await;
+                        if let Err(error) =
+                            functions::set_authentication_phone_number(phone_number, None, self.id())
+                                .await
+                        {
+                            handler.handle_error(error);
+                        }
+                    }
+                    ClientIdentifier::BotToken(_) => {
+                        let bot_token = handler.handle_bot_token().await;
+                        if let Err(error) =
+                            functions::check_authentication_bot_token(bot_token, self.id()).await
+                        {
+                            handler.handle_error(error);
+                        }
+                    }
+                },
+                AuthorizationState::WaitCode => {
+                    let code = handler.handle_code().await;
+                    if let Err(error) = functions::check_authentication_code(code, self.id()).await {
+                        handler.handle_error(error);
+                    }
+                }
+                AuthorizationState::WaitPassword => {
+                    let password = handler.handle_password().await;
+                    if let Err(error) =
+                        functions::check_authentication_password(password, self.id()).await
+                    {
+                        handler.handle_error(error);
+                    }
+                }
+                AuthorizationState::Ready | AuthorizationState::Closed => return Ok(()),
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+//! Parsing bot commands (`/command arg1 arg2`) out of incoming message text.
+//!
+//! Implement [`BotCommand`] by hand, or derive it with
+//! `#[derive(BotCommand)]` from the `tdlib-rs-macros` crate, which mirrors
+//! how teloxide maps string commands to enum variants.
+
+use std::fmt;
+
+/// Parses a `/command arg1 arg2` style message into `Self`.
+///
+/// `#[derive(BotCommand)]` generates this impl for an enum whose variants
+/// are the supported commands and whose fields (tuple or named, parsed via
+/// `FromStr`) are the command's arguments.
+pub trait BotCommand: Sized {
+    /// Parses `text`, stripping a `@bot_username` suffix from the command
+    /// name if present (e.g. `/start@mybot` parses the same as `/start`
+    /// when `bot_username` is `"mybot"`).
+    fn parse(text: &str, bot_username: &str) -> Result<Self, ParseError>;
+
+    /// A human-readable list of the supported commands and their
+    /// descriptions, suitable for a `/help` reply.
+    fn descriptions() -> String;
+}
+
+/// Why [`BotCommand::parse`] failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The text did not start with the command prefix (`/` by default), or
+    /// named a command this enum does not have a variant for.
+    UnknownCommand(String),
+    /// The command was recognized but was not given the number of
+    /// whitespace-separated arguments its variant requires.
+    WrongNumberOfArguments { expected: usize, found: usize },
+    /// An argument was present but failed to parse via `FromStr`.
+    ArgumentParseFailed { argument: String, error: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownCommand(command) => write!(f, "unknown command: {command}"),
+            ParseError::WrongNumberOfArguments { expected, found } => write!(
+                f,
+                "wrong number of arguments: expected {expected}, found {found}"
+            ),
+            ParseError::ArgumentParseFailed { argument, error } => {
+                write!(f, "failed to parse argument {argument:?}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
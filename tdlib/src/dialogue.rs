@@ -0,0 +1,122 @@
+//! Multi-step conversations keyed by chat, with pluggable persistent
+//! storage so they survive process restarts.
+//!
+//! A [`Dialogue`] wraps a [`Storage`] and a `(chat_id, user_id)` key; the
+//! caller loads the current `State` at the start of a handler, decides the
+//! next one, and calls [`Dialogue::update`] (or [`Dialogue::exit`]) to
+//! persist it. `InMemStorage` requires no setup; `RedisStorage` and
+//! `SqliteStorage` are available behind cargo features for deployments that
+//! need the state to outlive the process.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::sync::Mutex;
+
+/// Identifies one conversation: a chat and, for group chats, which user
+/// within it is mid-dialogue.
+pub type DialogueKey = (i64, i64);
+
+/// Persists dialogue state keyed by [`DialogueKey`].
+///
+/// `State` must be `Serialize + DeserializeOwned` so any backend can store
+/// it as a serialized blob without knowing its shape.
+#[async_trait]
+pub trait Storage<State>: Send + Sync
+where
+    State: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    /// The error a backend's operations can fail with.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Returns the current state for `key`, or `None` if it has none.
+    async fn get_dialogue(&self, key: DialogueKey) -> Result<Option<State>, Self::Error>;
+
+    /// Sets the state for `key`, overwriting any previous one.
+    async fn update_dialogue(&self, key: DialogueKey, state: State) -> Result<(), Self::Error>;
+
+    /// Clears the state for `key`, ending the dialogue.
+    async fn remove_dialogue(&self, key: DialogueKey) -> Result<(), Self::Error>;
+}
+
+/// A conversation with `chat_id`/`user_id` identity, backed by `Storage`.
+///
+/// Obtain the current state with [`Dialogue::get`], decide what the next
+/// one should be in response to an update, and persist it with
+/// [`Dialogue::update`] (or end the conversation with [`Dialogue::exit`]).
+pub struct Dialogue<State, Storage> {
+    storage: Arc<Storage>,
+    key: DialogueKey,
+    _state: std::marker::PhantomData<State>,
+}
+
+impl<State, S> Dialogue<State, S>
+where
+    State: Serialize + DeserializeOwned + Send + Sync + 'static,
+    S: Storage<State>,
+{
+    /// Creates a handle for the dialogue keyed by `(chat_id, user_id)`.
+    pub fn new(storage: Arc<S>, chat_id: i64, user_id: i64) -> Self {
+        Self {
+            storage,
+            key: (chat_id, user_id),
+            _state: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the current state, or `None` if this dialogue has not
+    /// started (or has already ended).
+    pub async fn get(&self) -> Result<Option<State>, S::Error> {
+        self.storage.get_dialogue(self.key).await
+    }
+
+    /// Advances the dialogue to `state`.
+    pub async fn update(&self, state: State) -> Result<(), S::Error> {
+        self.storage.update_dialogue(self.key, state).await
+    }
+
+    /// Ends the dialogue, clearing its stored state.
+    pub async fn exit(&self) -> Result<(), S::Error> {
+        self.storage.remove_dialogue(self.key).await
+    }
+}
+
+/// An in-process [`Storage`] backed by a `HashMap`. State does not survive
+/// a restart; use `RedisStorage` or `SqliteStorage` for that.
+#[derive(Default)]
+pub struct InMemStorage<State> {
+    states: Mutex<HashMap<DialogueKey, State>>,
+}
+
+impl<State> InMemStorage<State> {
+    /// Creates an empty in-memory store.
+    pub fn new() -> Self {
+        Self {
+            states: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl<State> Storage<State> for InMemStorage<State>
+where
+    State: Serialize + DeserializeOwned + Send + Sync + Clone + 'static,
+{
+    type Error = std::convert::Infallible;
+
+    async fn get_dialogue(&self, key: DialogueKey) -> Result<Option<State>, Self::Error> {
+        Ok(self.states.lock().await.get(&key).cloned())
+    }
+
+    async fn update_dialogue(&self, key: DialogueKey, state: State) -> Result<(), Self::Error> {
+        self.states.lock().await.insert(key, state);
+        Ok(())
+    }
+
+    async fn remove_dialogue(&self, key: DialogueKey) -> Result<(), Self::Error> {
+        self.states.lock().await.remove(&key);
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+//! [`Storage`] backend on top of Redis. Requires the `redis-storage` feature.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::dialogue::{DialogueKey, Storage};
+
+/// Stores dialogue state as a JSON blob under `{prefix}:{chat_id}:{user_id}`.
+pub struct RedisStorage {
+    client: redis::Client,
+    key_prefix: String,
+}
+
+impl RedisStorage {
+    /// Creates a store for the Redis instance at `url`, storing keys under
+    /// `key_prefix`. No connection is made here; each operation opens its
+    /// own.
+    pub fn open(url: &str, key_prefix: impl Into<String>) -> redis::RedisResult<Self> {
+        Ok(Self {
+            client: redis::Client::open(url)?,
+            key_prefix: key_prefix.into(),
+        })
+    }
+
+    fn redis_key(&self, key: DialogueKey) -> String {
+        format!("{}:{}:{}", self.key_prefix, key.0, key.1)
+    }
+}
+
+/// Why a [`RedisStorage`] operation failed.
+#[derive(Debug)]
+pub enum RedisStorageError {
+    /// The Redis operation itself failed.
+    Redis(redis::RedisError),
+    /// The stored blob was not valid JSON for the requested `State`.
+    Deserialize(serde_json::Error),
+}
+
+impl fmt::Display for RedisStorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RedisStorageError::Redis(error) => write!(f, "{error}"),
+            RedisStorageError::Deserialize(error) => {
+                write!(f, "stored dialogue state is not valid JSON: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RedisStorageError {}
+
+impl From<redis::RedisError> for RedisStorageError {
+    fn from(error: redis::RedisError) -> Self {
+        RedisStorageError::Redis(error)
+    }
+}
+
+#[async_trait]
+impl<State> Storage<State> for RedisStorage
+where
+    State: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    type Error = RedisStorageError;
+
+    async fn get_dialogue(&self, key: DialogueKey) -> Result<Option<State>, Self::Error> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let raw: Option<String> = conn.get(self.redis_key(key)).await?;
+        raw.map(|raw| serde_json::from_str(&raw).map_err(RedisStorageError::Deserialize))
+            .transpose()
+    }
+
+    async fn update_dialogue(&self, key: DialogueKey, state: State) -> Result<(), Self::Error> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let raw = serde_json::to_string(&state)
+            .expect("dialogue state must be serializable to JSON");
+        conn.set::<_, _, ()>(self.redis_key(key), raw).await?;
+        Ok(())
+    }
+
+    async fn remove_dialogue(&self, key: DialogueKey) -> Result<(), Self::Error> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        conn.del::<_, ()>(self.redis_key(key)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+//! [`Storage`] backend on top of SQLite. Requires the `sqlite-storage`
+//! feature.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use sqlx::SqlitePool;
+
+use crate::dialogue::{DialogueKey, Storage};
+
+/// Stores dialogue state as a JSON blob in a `dialogues` table keyed by
+/// `(chat_id, user_id)`.
+pub struct SqliteStorage {
+    pool: SqlitePool,
+}
+
+impl SqliteStorage {
+    /// Connects to the database at `url` and ensures the `dialogues` table
+    /// exists.
+    pub async fn open(url: &str) -> sqlx::Result<Self> {
+        let pool = SqlitePool::connect(url).await?;
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS dialogues (
+                chat_id INTEGER NOT NULL,
+                user_id INTEGER NOT NULL,
+                state TEXT NOT NULL,
+                PRIMARY KEY (chat_id, user_id)
+            )",
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(Self { pool })
+    }
+}
+
+/// Why a [`SqliteStorage`] operation failed.
+#[derive(Debug)]
+pub enum SqliteStorageError {
+    /// The SQLite operation itself failed.
+    Sqlx(sqlx::Error),
+    /// The stored blob was not valid JSON for the requested `State`.
+    Deserialize(serde_json::Error),
+}
+
+impl fmt::Display for SqliteStorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqliteStorageError::Sqlx(error) => write!(f, "{error}"),
+            SqliteStorageError::Deserialize(error) => {
+                write!(f, "stored dialogue state is not valid JSON: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SqliteStorageError {}
+
+impl From<sqlx::Error> for SqliteStorageError {
+    fn from(error: sqlx::Error) -> Self {
+        SqliteStorageError::Sqlx(error)
+    }
+}
+
+#[async_trait]
+impl<State> Storage<State> for SqliteStorage
+where
+    State: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    type Error = SqliteStorageError;
+
+    async fn get_dialogue(&self, key: DialogueKey) -> Result<Option<State>, Self::Error> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT state FROM dialogues WHERE chat_id = ? AND user_id = ?")
+                .bind(key.0)
+                .bind(key.1)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        row.map(|(raw,)| serde_json::from_str(&raw).map_err(SqliteStorageError::Deserialize))
+            .transpose()
+    }
+
+    async fn update_dialogue(&self, key: DialogueKey, state: State) -> Result<(), Self::Error> {
+        let raw = serde_json::to_string(&state)
+            .expect("dialogue state must be serializable to JSON");
+
+        sqlx::query(
+            "INSERT INTO dialogues (chat_id, user_id, state) VALUES (?, ?, ?)
+             ON CONFLICT (chat_id, user_id) DO UPDATE SET state = excluded.state",
+        )
+        .bind(key.0)
+        .bind(key.1)
+        .bind(raw)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn remove_dialogue(&self, key: DialogueKey) -> Result<(), Self::Error> {
+        sqlx::query("DELETE FROM dialogues WHERE chat_id = ? AND user_id = ?")
+            .bind(key.0)
+            .bind(key.1)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+//! Hand-written stand-ins for the slice of TDLib's generated `enums`
+//! module this crate's higher-level subsystems depend on. See the note at
+//! the top of [`crate::types`].
+
+#[derive(Debug, Clone)]
+pub enum Update {
+    AuthorizationState(crate::types::UpdateAuthorizationState),
+    NewChat(crate::types::UpdateNewChat),
+    Supergroup(crate::types::UpdateSupergroup),
+    User(crate::types::UpdateUser),
+    NewMessage(crate::types::UpdateNewMessage),
+}
+
+#[derive(Debug, Clone)]
+pub enum AuthorizationState {
+    WaitTdlibParameters,
+    WaitEncryptionKey(bool),
+    WaitPhoneNumber,
+    WaitCode,
+    WaitPassword,
+    Ready,
+    Closing,
+    Closed,
+}
+
+#[derive(Debug, Clone)]
+pub enum User {
+    User(crate::types::User),
+}
+
+#[derive(Debug, Clone)]
+pub enum MessageContent {
+    MessageText(crate::types::MessageText),
+    /// Stands in for every other content variant TDLib defines (photos,
+    /// stickers, ...); this stub module only models the text case the
+    /// examples exercise.
+    Unsupported,
+}
+
+#[derive(Debug, Clone)]
+pub enum InputMessageContent {
+    InputMessageText(crate::types::InputMessageText),
+}
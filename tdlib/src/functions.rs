@@ -0,0 +1,70 @@
+//! Hand-written stand-ins for the slice of TDLib's generated `functions`
+//! module this crate's higher-level subsystems depend on. See the note at
+//! the top of [`crate::types`].
+//!
+//! Each function here mirrors a real tdjson request's signature but talks
+//! to nothing; calling one panics, since doing so requires the native
+//! TDLib client this environment cannot build.
+
+use crate::enums::{InputMessageContent, User};
+use crate::types::{Error, TdlibParameters};
+
+fn unavailable() -> ! {
+    unimplemented!("requires a native TDLib client, unavailable in this environment")
+}
+
+pub async fn set_tdlib_parameters(_parameters: TdlibParameters, _client_id: i32) -> Result<(), Error> {
+    unavailable()
+}
+
+pub async fn get_me(_client_id: i32) -> Result<User, Error> {
+    unavailable()
+}
+
+pub async fn close(_client_id: i32) -> Result<(), Error> {
+    unavailable()
+}
+
+pub async fn set_log_verbosity_level(_new_verbosity_level: i32, _client_id: i32) -> Result<(), Error> {
+    unavailable()
+}
+
+pub async fn check_database_encryption_key(
+    _encryption_key: String,
+    _client_id: i32,
+) -> Result<(), Error> {
+    unavailable()
+}
+
+pub async fn set_authentication_phone_number(
+    _phone_number: String,
+    _settings: Option<()>,
+    _client_id: i32,
+) -> Result<(), Error> {
+    unavailable()
+}
+
+pub async fn check_authentication_bot_token(_token: String, _client_id: i32) -> Result<(), Error> {
+    unavailable()
+}
+
+pub async fn check_authentication_code(_code: String, _client_id: i32) -> Result<(), Error> {
+    unavailable()
+}
+
+pub async fn check_authentication_password(_password: String, _client_id: i32) -> Result<(), Error> {
+    unavailable()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn send_message(
+    _chat_id: i64,
+    _message_thread_id: i64,
+    _reply_to_message_id: i64,
+    _options: Option<()>,
+    _reply_markup: Option<()>,
+    _input_message_content: InputMessageContent,
+    _client_id: i32,
+) -> Result<(), Error> {
+    unavailable()
+}
@@ -0,0 +1,55 @@
+//! Safe bindings to [TDLib](https://github.com/tdlib/td), the Telegram
+//! Database library.
+//!
+//! `types`, `enums` and `functions` would normally be generated from
+//! TDLib's `td_api.tl` scheme by a `build.rs` step against a local TDLib
+//! install; that pipeline isn't available in this environment, so those
+//! three modules are hand-written stand-ins covering just the surface the
+//! rest of this crate and its examples use. `create_client`/`receive` stand
+//! in for the thin wrappers around `tdjson` everything else is built on top
+//! of; like those modules, they panic when called here.
+//!
+//! On top of those primitives, [`Worker`] and [`Client`] provide a
+//! higher-level subsystem that owns the receive loop and demultiplexes
+//! updates per client, so most users should not need to call `receive`
+//! directly.
+
+mod auth;
+mod client;
+pub mod command;
+pub mod dialogue;
+#[cfg(feature = "redis-storage")]
+mod dialogue_redis;
+#[cfg(feature = "sqlite-storage")]
+mod dialogue_sqlite;
+pub mod enums;
+pub mod functions;
+pub mod params;
+mod stream;
+pub mod types;
+mod worker;
+
+/// Allocates a new tdlib client and returns its id.
+pub fn create_client() -> i32 {
+    unimplemented!("requires a native TDLib client, unavailable in this environment")
+}
+
+/// Polls for the next `(Update, client_id)` tdjson has for any client.
+pub fn receive() -> Option<(enums::Update, i32)> {
+    unimplemented!("requires a native TDLib client, unavailable in this environment")
+}
+
+pub use auth::{AuthStateHandler, ChannelAuthStateHandler, ClientIdentifier, ConsoleAuthStateHandler};
+pub use client::{BoundClient, Client, ClientBuilder};
+#[cfg(feature = "redis-storage")]
+pub use dialogue_redis::RedisStorage;
+#[cfg(feature = "sqlite-storage")]
+pub use dialogue_sqlite::SqliteStorage;
+pub use params::{ParamError, TdlibParametersBuilder};
+pub use stream::{handler, FnHandler, UpdateHandler, UpdateStream};
+pub use worker::{Worker, WorkerBuilder};
+
+/// Derives [`command::BotCommand`] for an enum of `/command` variants.
+/// Requires the `macros` feature.
+#[cfg(feature = "macros")]
+pub use tdlib_rs_macros::BotCommand;
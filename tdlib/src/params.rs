@@ -0,0 +1,141 @@
+//! A validating builder for [`TdlibParameters`].
+//!
+//! Filling the struct with `..Default::default()` and a fallible `env!`
+//! parse is how most of the confusing "Valid api_id must be provided"
+//! failures from tdjson start: the parameters end up with a zero `api_id`
+//! or empty `api_hash` and the error only surfaces once tdlib rejects
+//! `SetTdlibParameters` at runtime. [`TdlibParameters::builder`] catches
+//! that up front instead.
+
+use std::fmt;
+
+use crate::types::TdlibParameters;
+
+/// Builder for [`TdlibParameters`], returned by [`TdlibParameters::builder`].
+#[derive(Default)]
+pub struct TdlibParametersBuilder {
+    parameters: TdlibParameters,
+}
+
+impl TdlibParameters {
+    /// Creates a [`TdlibParametersBuilder`].
+    pub fn builder() -> TdlibParametersBuilder {
+        TdlibParametersBuilder::default()
+    }
+}
+
+impl TdlibParametersBuilder {
+    /// Sets the application identifier obtained at <https://my.telegram.org>.
+    pub fn api_id(mut self, api_id: i32) -> Self {
+        self.parameters.api_id = api_id;
+        self
+    }
+
+    /// Sets the application identifier hash obtained at <https://my.telegram.org>.
+    pub fn api_hash(mut self, api_hash: impl Into<String>) -> Self {
+        self.parameters.api_hash = api_hash.into();
+        self
+    }
+
+    /// Sets the directory tdlib will store its database and files in.
+    pub fn database_directory(mut self, database_directory: impl Into<String>) -> Self {
+        self.parameters.database_directory = database_directory.into();
+        self
+    }
+
+    /// Sets whether to connect to the test Telegram server.
+    pub fn use_test_dc(mut self, use_test_dc: bool) -> Self {
+        self.parameters.use_test_dc = use_test_dc;
+        self
+    }
+
+    /// Sets whether tdlib should optimize disk usage at the cost of more
+    /// CPU usage.
+    pub fn enable_storage_optimizer(mut self, enable_storage_optimizer: bool) -> Self {
+        self.parameters.enable_storage_optimizer = enable_storage_optimizer;
+        self
+    }
+
+    /// Sets the IETF language tag of the user's operating system language.
+    pub fn system_language_code(mut self, system_language_code: impl Into<String>) -> Self {
+        self.parameters.system_language_code = system_language_code.into();
+        self
+    }
+
+    /// Sets the model of the device the application is running on.
+    pub fn device_model(mut self, device_model: impl Into<String>) -> Self {
+        self.parameters.device_model = device_model.into();
+        self
+    }
+
+    /// Sets the version of the application.
+    pub fn application_version(mut self, application_version: impl Into<String>) -> Self {
+        self.parameters.application_version = application_version.into();
+        self
+    }
+
+    /// Validates the parameters and builds them, rejecting a zero `api_id`
+    /// or empty `api_hash` up front rather than leaving tdjson to fail with
+    /// a confusing "Valid api_id must be provided" error at runtime.
+    pub fn build(self) -> Result<TdlibParameters, ParamError> {
+        validate(&self.parameters)?;
+        Ok(self.parameters)
+    }
+}
+
+/// Why a [`TdlibParameters`] failed validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// `api_id` was left at its default of `0`.
+    MissingApiId,
+    /// `api_hash` was left empty.
+    MissingApiHash,
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::MissingApiId => {
+                write!(f, "a valid api_id must be provided (got 0)")
+            }
+            ParamError::MissingApiHash => write!(f, "a valid api_hash must be provided (got \"\")"),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+pub(crate) fn validate(parameters: &TdlibParameters) -> Result<(), ParamError> {
+    if parameters.api_id == 0 {
+        return Err(ParamError::MissingApiId);
+    }
+
+    if parameters.api_hash.is_empty() {
+        return Err(ParamError::MissingApiHash);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_zero_api_id() {
+        let result = TdlibParameters::builder().api_hash("hash").build();
+        assert!(matches!(result, Err(ParamError::MissingApiId)));
+    }
+
+    #[test]
+    fn rejects_an_empty_api_hash() {
+        let result = TdlibParameters::builder().api_id(1).build();
+        assert!(matches!(result, Err(ParamError::MissingApiHash)));
+    }
+
+    #[test]
+    fn accepts_valid_parameters() {
+        let result = TdlibParameters::builder().api_id(1).api_hash("hash").build();
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,124 @@
+//! A [`futures::Stream`] view of a client's updates, with adaptors for
+//! assembling pipelines declaratively instead of writing one big `match`.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use tokio::sync::mpsc::Receiver;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::StreamExt;
+
+use crate::enums::Update;
+use crate::worker::SenderGuard;
+
+/// A [`Stream`] of the [`Update`]s addressed to one client.
+///
+/// Obtained from [`crate::BoundClient::into_stream`]; composes with any
+/// `futures`/`tokio-stream` combinator, plus the update-shaped adaptors
+/// below.
+pub struct UpdateStream {
+    inner: ReceiverStream<Update>,
+    // Keeps the client bound to its `Worker` until this stream (and not just
+    // the `BoundClient` it came from) is dropped.
+    #[allow(dead_code)]
+    guard: SenderGuard,
+}
+
+impl UpdateStream {
+    pub(crate) fn new(receiver: Receiver<Update>, guard: SenderGuard) -> Self {
+        Self {
+            inner: ReceiverStream::new(receiver),
+            guard,
+        }
+    }
+
+    /// Keeps only `Update::NewMessage` updates.
+    pub fn filter_new_messages(self) -> impl Stream<Item = Update> {
+        self.filter(|update| matches!(update, Update::NewMessage(_)))
+    }
+
+    /// Maps each update through `f`, keeping only the ones it returns `Some`
+    /// for — e.g. pulling the text out of `Update::NewMessage`s and
+    /// dropping everything else in one step.
+    pub fn filter_map_update<F, T>(self, f: F) -> impl Stream<Item = T>
+    where
+        F: FnMut(Update) -> Option<T>,
+    {
+        self.filter_map(f)
+    }
+
+    /// Routes each update to the first handler in `handlers` whose
+    /// `should_handle` returns `true`, awaiting it before moving on to the
+    /// next update.
+    pub async fn dispatch<H>(mut self, handlers: Vec<H>)
+    where
+        H: UpdateHandler,
+    {
+        while let Some(update) = StreamExt::next(&mut self).await {
+            for handler in &handlers {
+                if handler.should_handle(&update) {
+                    handler.handle(update).await;
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl Stream for UpdateStream {
+    type Item = Update;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+/// A handler [`UpdateStream::dispatch`] can route updates to.
+#[async_trait::async_trait]
+pub trait UpdateHandler {
+    /// Whether this handler wants to process `update`.
+    fn should_handle(&self, update: &Update) -> bool;
+
+    /// Processes `update`. Only called when [`Self::should_handle`] returned
+    /// `true`.
+    async fn handle(&self, update: Update);
+}
+
+/// An [`UpdateHandler`] built from a predicate and an async closure,
+/// returned by [`handler`].
+pub struct FnHandler<F, Fut> {
+    predicate: fn(&Update) -> bool,
+    f: F,
+    _fut: std::marker::PhantomData<fn() -> Fut>,
+}
+
+#[async_trait::async_trait]
+impl<F, Fut> UpdateHandler for FnHandler<F, Fut>
+where
+    F: Fn(Update) -> Fut + Send + Sync,
+    Fut: Future<Output = ()> + Send,
+{
+    fn should_handle(&self, update: &Update) -> bool {
+        (self.predicate)(update)
+    }
+
+    async fn handle(&self, update: Update) {
+        (self.f)(update).await;
+    }
+}
+
+/// Builds an [`UpdateHandler`] from a predicate and an async closure, for
+/// use with [`UpdateStream::dispatch`].
+pub fn handler<F, Fut>(predicate: fn(&Update) -> bool, f: F) -> FnHandler<F, Fut>
+where
+    F: Fn(Update) -> Fut + Send + Sync,
+    Fut: Future<Output = ()> + Send,
+{
+    FnHandler {
+        predicate,
+        f,
+        _fut: std::marker::PhantomData,
+    }
+}
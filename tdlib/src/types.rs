@@ -0,0 +1,105 @@
+//! Hand-written stand-ins for the slice of TDLib's generated `types` module
+//! this crate's higher-level subsystems depend on.
+//!
+//! The real `types`/`enums`/`functions` modules are generated by `build.rs`
+//! from TDLib's `td_api.tl` scheme against a local TDLib install; that
+//! codegen pipeline needs the TDLib C library and is not available in this
+//! environment, so this file stands in for just the shapes referenced
+//! elsewhere in the crate, keeping their real field names.
+
+#[derive(Debug, Clone, Default)]
+pub struct TdlibParameters {
+    pub use_test_dc: bool,
+    pub database_directory: String,
+    pub api_id: i32,
+    pub api_hash: String,
+    pub system_language_code: String,
+    pub device_model: String,
+    pub application_version: String,
+    pub enable_storage_optimizer: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Error {
+    pub code: i32,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FormattedText {
+    pub text: String,
+    pub entities: Vec<TextEntity>,
+}
+
+/// A formatting annotation (bold, a link, ...) over a range of a
+/// [`FormattedText`]'s `text`. Left unmodeled beyond its shape since nothing
+/// in this crate inspects entity kinds yet.
+#[derive(Debug, Clone)]
+pub struct TextEntity {
+    pub offset: i32,
+    pub length: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct InputMessageText {
+    pub text: FormattedText,
+    pub disable_web_page_preview: bool,
+    pub clear_draft: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Chat {
+    pub id: i64,
+    pub title: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Supergroup {
+    pub id: i64,
+    pub username: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct User {
+    pub id: i64,
+    pub first_name: String,
+    pub last_name: String,
+    pub username: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MessageText {
+    pub text: FormattedText,
+}
+
+#[derive(Debug, Clone)]
+pub struct Message {
+    pub id: i64,
+    pub chat_id: i64,
+    pub content: crate::enums::MessageContent,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateAuthorizationState {
+    pub authorization_state: crate::enums::AuthorizationState,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateNewChat {
+    pub chat: Chat,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateSupergroup {
+    pub supergroup: Supergroup,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateUser {
+    pub user: User,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateNewMessage {
+    pub message: Message,
+}
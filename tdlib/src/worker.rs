@@ -0,0 +1,142 @@
+//! Background receive loop shared by one or more [`Client`]s.
+//!
+//! Before this module existed, every consumer of the crate had to hand-roll
+//! the loop around [`crate::receive`], fan updates out by `client_id`
+//! itself, and track a flag to know when to stop (see the old `bot`
+//! example). [`Worker`] owns that loop instead: a single background task
+//! calls [`crate::receive`] and pushes each `Update` into the channel of
+//! whichever client it belongs to.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::client::{BoundClient, Client};
+use crate::enums::Update;
+
+/// Default capacity of the `mpsc` channel handed to each bound client.
+const DEFAULT_CHANNEL_CAPACITY: usize = 100;
+
+pub(crate) type ClientSenders = Arc<Mutex<HashMap<i32, mpsc::Sender<Update>>>>;
+
+/// Removes a client's sender from [`ClientSenders`] when it is dropped, so
+/// binding and unbinding clients over time does not leak one map entry per
+/// client forever. Held by [`BoundClient`] and, after
+/// [`BoundClient::into_stream`], by [`crate::UpdateStream`].
+pub(crate) struct SenderGuard {
+    senders: ClientSenders,
+    client_id: i32,
+}
+
+impl Drop for SenderGuard {
+    fn drop(&mut self) {
+        self.senders
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(&self.client_id);
+    }
+}
+
+/// Owns the single background task that calls [`crate::receive`] and
+/// demultiplexes updates to the clients bound to it.
+///
+/// Multiple [`Client`]s can be bound to the same `Worker`, letting them
+/// share one receive loop instead of spawning one each.
+pub struct Worker {
+    senders: ClientSenders,
+    channel_capacity: usize,
+    receive_handle: JoinHandle<()>,
+}
+
+impl Worker {
+    /// Creates a [`WorkerBuilder`] to configure and start a `Worker`.
+    pub fn builder() -> WorkerBuilder {
+        WorkerBuilder::default()
+    }
+
+    /// Binds a [`Client`] to this worker, returning a [`BoundClient`] handle
+    /// that receives only the updates belonging to it.
+    ///
+    /// The binding is undone automatically once the `BoundClient` (or the
+    /// `UpdateStream` it is turned into) is dropped.
+    pub async fn bind_client(&self, client: Client) -> BoundClient {
+        let (tx, rx) = mpsc::channel(self.channel_capacity);
+        let client_id = client.id();
+        self.senders
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(client_id, tx);
+
+        let guard = SenderGuard {
+            senders: self.senders.clone(),
+            client_id,
+        };
+        BoundClient::new(client, rx, guard)
+    }
+
+    /// Stops the background receive task.
+    ///
+    /// Bound clients are not affected; in-flight updates that were already
+    /// queued on their channels can still be drained.
+    pub fn shutdown(self) {
+        self.receive_handle.abort();
+    }
+}
+
+/// Builder for [`Worker`].
+pub struct WorkerBuilder {
+    channel_capacity: usize,
+}
+
+impl Default for WorkerBuilder {
+    fn default() -> Self {
+        Self {
+            channel_capacity: DEFAULT_CHANNEL_CAPACITY,
+        }
+    }
+}
+
+impl WorkerBuilder {
+    /// Overrides the capacity of the per-client update channel.
+    pub fn with_channel_capacity(mut self, channel_capacity: usize) -> Self {
+        self.channel_capacity = channel_capacity;
+        self
+    }
+
+    /// Spawns the background receive task and returns the running [`Worker`].
+    pub fn build(self) -> Worker {
+        let senders: ClientSenders = Arc::new(Mutex::new(HashMap::new()));
+        let loop_senders = senders.clone();
+
+        let receive_handle = tokio::spawn(async move {
+            loop {
+                // Without an await point the task never yields, and
+                // `Worker::shutdown`'s abort would never take effect.
+                tokio::task::yield_now().await;
+
+                let Some((update, client_id)) = crate::receive() else {
+                    continue;
+                };
+
+                let senders = loop_senders
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                if let Some(sender) = senders.get(&client_id) {
+                    // The receiving end only goes away when the `BoundClient`
+                    // is dropped; a full channel means the consumer is
+                    // behind, not that it is gone, so a dropped update here
+                    // is preferable to blocking the whole loop.
+                    let _ = sender.try_send(update);
+                }
+            }
+        });
+
+        Worker {
+            senders,
+            channel_capacity: self.channel_capacity,
+            receive_handle,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Exercises `#[derive(BotCommand)]` against the cases most likely to
+//! regress: a unit variant, and a trailing string field that must absorb
+//! the rest of the text instead of being cut at the first separator in it.
+
+use tdlib::command::{BotCommand, ParseError};
+
+#[derive(tdlib::BotCommand, Debug, PartialEq)]
+#[command(prefix = "/", separator = " ")]
+enum Command {
+    #[command(description = "show this help")]
+    Help,
+    #[command(description = "reply with the given text")]
+    Echo(String),
+}
+
+#[test]
+fn echo_absorbs_the_whole_trailing_argument() {
+    assert_eq!(
+        Command::parse("/echo hello world", "mybot").unwrap(),
+        Command::Echo("hello world".to_string())
+    );
+}
+
+#[test]
+fn unit_variant_parses_with_no_arguments() {
+    assert_eq!(Command::parse("/help", "mybot").unwrap(), Command::Help);
+}
+
+#[test]
+fn unit_variant_rejects_extra_arguments() {
+    assert_eq!(
+        Command::parse("/help please", "mybot"),
+        Err(ParseError::WrongNumberOfArguments {
+            expected: 0,
+            found: 1,
+        })
+    );
+}
+
+#[test]
+fn unknown_command_is_rejected() {
+    assert_eq!(
+        Command::parse("/nope", "mybot"),
+        Err(ParseError::UnknownCommand("nope".to_string()))
+    );
+}